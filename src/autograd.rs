@@ -1,4 +1,4 @@
-use ndarray::Array2;
+use ndarray::{Array2, Axis};
 use std::cell::RefCell;
 use std::collections::HashSet;
 use std::rc::Rc;
@@ -6,19 +6,60 @@ use std::rc::Rc;
 #[derive(Debug, Clone, Copy)]
 enum Op {
     Add,
+    Sub,
     Mul,
+    Div,
+    Neg,
     Tanh,
     ReLU,
+    Exp,
+    Ln,
+    Sigmoid,
+    ElemMul,
+    Custom,
     None,
 }
 
+// Sums a gradient down to `shape` along any axis where `shape` is 1 but
+// `grad` is not, undoing the broadcast a forward op performed. Mirrors
+// NumPy-style broadcasting semantics for the (1,1)-operand case used
+// throughout this scalar-graph style.
+fn sum_to_shape(grad: &Array2<f64>, shape: (usize, usize)) -> Array2<f64> {
+    let mut result = grad.clone();
+    if shape.0 == 1 && result.shape()[0] != 1 {
+        result = result.sum_axis(Axis(0)).insert_axis(Axis(0));
+    }
+    if shape.1 == 1 && result.shape()[1] != 1 {
+        result = result.sum_axis(Axis(1)).insert_axis(Axis(1));
+    }
+    result
+}
+
+// A node's backward rule: reads its own grad and writes into its children's
+// grad. Wrapped in `Rc` (rather than `Box`) so it can be cloned out of the
+// node's `RefCell` before running, avoiding a borrow conflict when the rule
+// itself needs to borrow the node it came from.
+type BackwardFn = Rc<dyn Fn()>;
+
+// Reports a node's direct parents in the graph. `build_topo` only calls
+// this when `backward()` actually walks the graph, so no node carries a
+// `Vec<Autograd>` edge-list field that has to be populated during every
+// forward call whether or not the graph is ever differentiated. The
+// closure still strongly captures its parents (it has to — that's what
+// lets it write into their grad), so a node reachable from a live output
+// keeps its whole ancestry alive exactly as the old field did; this does
+// not bound peak memory on its own. `checkpoint()` is what actually drops
+// intermediate activations, by not keeping such a closure's parents around
+// at all between backward passes.
+type ParentsFn = Rc<dyn Fn() -> Vec<Autograd>>;
+
 // Inner data structure
 struct AutogradData {
     value: Array2<f64>,
     grad: Array2<f64>,
-    children: Vec<Autograd>,
+    parents: Option<ParentsFn>,
     op: Op,
-    backward: Option<fn(&AutogradData)>,
+    backward: Option<BackwardFn>,
 }
 
 // Wrapper with Rc for shared ownership
@@ -31,34 +72,164 @@ impl Autograd {
             data: Rc::new(RefCell::new(AutogradData {
                 grad: Array2::zeros((value.shape()[0], value.shape()[1])),
                 value,
-                children: Vec::new(),
+                parents: None,
                 op: Op::None,
                 backward: None,
             })),
         }
     }
 
+    // Builds a node carrying a custom forward value and backward rule,
+    // without needing a new `Op` variant or a branch in `backward()`. This
+    // is the extension point other modules (e.g. `loss`) use to define
+    // fused operations outside this file. `backward` receives the node's
+    // own (upstream) gradient and is responsible for accumulating into
+    // `children`'s grads via the public `grad`/`set_grad` API.
+    pub fn new_op(
+        value: Array2<f64>,
+        children: Vec<Autograd>,
+        backward: impl Fn(&Array2<f64>) + 'static,
+    ) -> Autograd {
+        let result = Autograd::new(value);
+        result.data.borrow_mut().op = Op::Custom;
+        result.data.borrow_mut().parents = Some(Rc::new(move || children.clone()));
+
+        // `result` owns this closure, so capturing a strong clone of itself
+        // here (as `out`) would make the closure keep the node alive forever
+        // (data -> backward Fn -> out -> data), leaking every node for the
+        // life of the process. A `Weak` breaks the cycle: the closure can
+        // only run while something else (the topo order built in
+        // `backward_bounded`) is holding a strong reference to the node.
+        let out = Rc::downgrade(&result.data);
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            backward(&grad);
+        }));
+
+        result
+    }
+
     // method to add two Autograd objects
     pub fn add(&self, other: &Autograd) -> Autograd {
         let value = &self.data.borrow().value + &other.data.borrow().value;
-
         let result = Autograd::new(value);
-        result.data.borrow_mut().children.push(self.clone());
-        result.data.borrow_mut().children.push(other.clone());
+
         result.data.borrow_mut().op = Op::Add;
-        result.data.borrow_mut().backward = Some(|_| {});
+        let (a_parent, b_parent) = (self.clone(), other.clone());
+        result.data.borrow_mut().parents = Some(Rc::new(move || vec![a_parent.clone(), b_parent.clone()]));
+
+        let out = Rc::downgrade(&result.data);
+        let a = self.clone();
+        let b = other.clone();
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            // y = a + b -> da = dy, db = dy, reduced back down for any
+            // operand (e.g. a bias) that was broadcast up to y's shape
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            let a_shape = a.data.borrow().value.dim();
+            let b_shape = b.data.borrow().value.dim();
+
+            a.data.borrow_mut().grad += &sum_to_shape(&grad, a_shape);
+            b.data.borrow_mut().grad += &sum_to_shape(&grad, b_shape);
+        }));
+
+        result
+    }
+
+    // method to subtract two Autograd objects
+    pub fn sub(&self, other: &Autograd) -> Autograd {
+        let value = &self.data.borrow().value - &other.data.borrow().value;
+        let result = Autograd::new(value);
+
+        result.data.borrow_mut().op = Op::Sub;
+        let (a_parent, b_parent) = (self.clone(), other.clone());
+        result.data.borrow_mut().parents = Some(Rc::new(move || vec![a_parent.clone(), b_parent.clone()]));
+
+        let out = Rc::downgrade(&result.data);
+        let a = self.clone();
+        let b = other.clone();
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            // y = a - b -> da = dy, db = -dy
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            a.data.borrow_mut().grad += &grad;
+            b.data.borrow_mut().grad -= &grad;
+        }));
 
         result
     }
 
     pub fn mul(&self, other: &Autograd) -> Autograd {
-        let value = &self.data.borrow().value.dot(&other.data.borrow().value);
+        let value = self.data.borrow().value.dot(&other.data.borrow().value);
+        let result = Autograd::new(value);
 
-        let result = Autograd::new(value.clone());
-        result.data.borrow_mut().children.push(self.clone());
-        result.data.borrow_mut().children.push(other.clone());
         result.data.borrow_mut().op = Op::Mul;
-        result.data.borrow_mut().backward = Some(|_| {});
+        let (a_parent, b_parent) = (self.clone(), other.clone());
+        result.data.borrow_mut().parents = Some(Rc::new(move || vec![a_parent.clone(), b_parent.clone()]));
+
+        let out = Rc::downgrade(&result.data);
+        let a = self.clone();
+        let b = other.clone();
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            // y = a * b -> da = dy * b^T, db = a^T * dy
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            let v0 = a.data.borrow().value.clone();
+            let v1 = b.data.borrow().value.clone();
+
+            a.data.borrow_mut().grad += &grad.dot(&v1.t());
+            b.data.borrow_mut().grad += &v0.t().dot(&grad);
+        }));
+
+        result
+    }
+
+    // method to divide two Autograd objects (elementwise)
+    pub fn div(&self, other: &Autograd) -> Autograd {
+        let value = &self.data.borrow().value / &other.data.borrow().value;
+        let result = Autograd::new(value);
+
+        result.data.borrow_mut().op = Op::Div;
+        let (a_parent, b_parent) = (self.clone(), other.clone());
+        result.data.borrow_mut().parents = Some(Rc::new(move || vec![a_parent.clone(), b_parent.clone()]));
+
+        let out = Rc::downgrade(&result.data);
+        let a = self.clone();
+        let b = other.clone();
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            // y = a / b -> da = dy / b, db = -dy * a / b^2, reduced back
+            // down for any operand that was broadcast up to y's shape
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            let v0 = a.data.borrow().value.clone();
+            let v1 = b.data.borrow().value.clone();
+            let a_shape = (v0.shape()[0], v0.shape()[1]);
+            let b_shape = (v1.shape()[0], v1.shape()[1]);
+
+            a.data.borrow_mut().grad += &sum_to_shape(&(&grad / &v1), a_shape);
+            b.data.borrow_mut().grad -= &sum_to_shape(&(&grad * &v0 / (&v1 * &v1)), b_shape);
+        }));
+
+        result
+    }
+
+    pub fn neg(&self) -> Autograd {
+        let value = self.data.borrow().value.mapv(|x| -x);
+        let result = Autograd::new(value);
+
+        result.data.borrow_mut().op = Op::Neg;
+        let a_parent = self.clone();
+        result.data.borrow_mut().parents = Some(Rc::new(move || vec![a_parent.clone()]));
+
+        let out = Rc::downgrade(&result.data);
+        let a = self.clone();
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            // y = -a -> da = -dy
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            a.data.borrow_mut().grad -= &grad;
+        }));
 
         result
     }
@@ -67,9 +238,21 @@ impl Autograd {
         let value = self.data.borrow().value.mapv(|x| x.tanh());
         let result = Autograd::new(value);
 
-        result.data.borrow_mut().children.push(self.clone());
         result.data.borrow_mut().op = Op::Tanh;
-        result.data.borrow_mut().backward = Some(|_| {});
+        let a_parent = self.clone();
+        result.data.borrow_mut().parents = Some(Rc::new(move || vec![a_parent.clone()]));
+
+        let out = Rc::downgrade(&result.data);
+        let a = self.clone();
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            // y = tanh(x) -> dy/dx = 1 - tanh(x)^2
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            let y = out.borrow().value.clone();
+            let local_deriv = y.mapv(|x| 1.0 - x * x);
+
+            a.data.borrow_mut().grad += &(&local_deriv * &grad);
+        }));
 
         result
     }
@@ -78,74 +261,171 @@ impl Autograd {
         let value = self.data.borrow().value.mapv(|x| x.max(0.0));
         let result = Autograd::new(value);
 
-        result.data.borrow_mut().children.push(self.clone());
         result.data.borrow_mut().op = Op::ReLU;
-        result.data.borrow_mut().backward = Some(|_| {});
+        let a_parent = self.clone();
+        result.data.borrow_mut().parents = Some(Rc::new(move || vec![a_parent.clone()]));
+
+        let out = Rc::downgrade(&result.data);
+        let a = self.clone();
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            // y = relu(x) -> dy/dx = 1 if x > 0, 0 otherwise
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            let y = out.borrow().value.clone();
+            let mask = grad * y.mapv(|x| if x > 0.0 { 1.0 } else { 0.0 });
+
+            a.data.borrow_mut().grad += &mask;
+        }));
+
+        result
+    }
+
+    // elementwise (Hadamard) multiply, distinct from the matmul `mul`.
+    // Broadcasts a (1,1) operand against the other operand's shape.
+    pub fn elem_mul(&self, other: &Autograd) -> Autograd {
+        let value = &self.data.borrow().value * &other.data.borrow().value;
+        let result = Autograd::new(value);
+
+        result.data.borrow_mut().op = Op::ElemMul;
+        let (a_parent, b_parent) = (self.clone(), other.clone());
+        result.data.borrow_mut().parents = Some(Rc::new(move || vec![a_parent.clone(), b_parent.clone()]));
+
+        let out = Rc::downgrade(&result.data);
+        let a = self.clone();
+        let b = other.clone();
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            // y = a * b -> da = dy * b, db = dy * a
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            let v0 = a.data.borrow().value.clone();
+            let v1 = b.data.borrow().value.clone();
+
+            let a_shape = (v0.shape()[0], v0.shape()[1]);
+            let b_shape = (v1.shape()[0], v1.shape()[1]);
+
+            a.data.borrow_mut().grad += &sum_to_shape(&(&grad * &v1), a_shape);
+            b.data.borrow_mut().grad += &sum_to_shape(&(&grad * &v0), b_shape);
+        }));
+
+        result
+    }
+
+    pub fn exp(&self) -> Autograd {
+        let value = self.data.borrow().value.mapv(|x| x.exp());
+        let result = Autograd::new(value);
+
+        result.data.borrow_mut().op = Op::Exp;
+        let a_parent = self.clone();
+        result.data.borrow_mut().parents = Some(Rc::new(move || vec![a_parent.clone()]));
+
+        let out = Rc::downgrade(&result.data);
+        let a = self.clone();
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            // y = exp(x) -> dy/dx = exp(x) = y
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            let y = out.borrow().value.clone();
+
+            a.data.borrow_mut().grad += &(&grad * &y);
+        }));
+
+        result
+    }
+
+    pub fn ln(&self) -> Autograd {
+        let value = self.data.borrow().value.mapv(|x| x.ln());
+        let result = Autograd::new(value);
+
+        result.data.borrow_mut().op = Op::Ln;
+        let a_parent = self.clone();
+        result.data.borrow_mut().parents = Some(Rc::new(move || vec![a_parent.clone()]));
+
+        let out = Rc::downgrade(&result.data);
+        let a = self.clone();
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            // y = ln(x) -> dy/dx = 1/x
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            let x = a.data.borrow().value.clone();
+
+            a.data.borrow_mut().grad += &(&grad / &x);
+        }));
+
+        result
+    }
+
+    pub fn sigmoid(&self) -> Autograd {
+        let value = self.data.borrow().value.mapv(|x| 1.0 / (1.0 + (-x).exp()));
+        let result = Autograd::new(value);
+
+        result.data.borrow_mut().op = Op::Sigmoid;
+        let a_parent = self.clone();
+        result.data.borrow_mut().parents = Some(Rc::new(move || vec![a_parent.clone()]));
+
+        let out = Rc::downgrade(&result.data);
+        let a = self.clone();
+        result.data.borrow_mut().backward = Some(Rc::new(move || {
+            // y = sigmoid(x) -> dy/dx = y * (1 - y)
+            let out = out.upgrade().expect("node dropped before its own backward ran");
+            let grad = out.borrow().grad.clone();
+            let y = out.borrow().value.clone();
+            let local_deriv = y.mapv(|s| s * (1.0 - s));
+
+            a.data.borrow_mut().grad += &(&local_deriv * &grad);
+        }));
 
         result
     }
 
+    // `boundary`, when set, stops the walk at the node with that id: its
+    // grad still gets populated by whichever op feeds it, but neither its
+    // own parents nor its own backward rule are visited. Used by
+    // `checkpoint()`'s recompute so a checkpoint's local backward pass
+    // doesn't re-run the rest of the tape beyond its own input.
+    //
+    // The topo order itself is only built here, on demand, by invoking each
+    // node's `parents` accessor as the walk reaches it — no node keeps a
+    // standing `Vec<Autograd>` edge-list field. That accessor still holds
+    // its parents strongly, though (see `ParentsFn`), so this doesn't by
+    // itself shrink the graph's memory footprint; use `checkpoint()` for
+    // that.
     fn build_topo(
         &self,
         topo: &mut Vec<Autograd>,
         visited: &mut HashSet<*const RefCell<AutogradData>>,
+        boundary: Option<usize>,
     ) {
         let ptr = Rc::as_ptr(&self.data);
         if !visited.contains(&ptr) {
             visited.insert(ptr);
-            for child in &self.data.borrow().children {
-                child.build_topo(topo, visited);
+            if boundary != Some(self.id()) {
+                let parents = self.data.borrow().parents.clone();
+                if let Some(parents) = parents {
+                    for parent in parents() {
+                        parent.build_topo(topo, visited, boundary);
+                    }
+                }
             }
             topo.push(self.clone());
         }
     }
 
     pub fn backward(&self) {
+        self.backward_bounded(None);
+    }
+
+    fn backward_bounded(&self, boundary: Option<usize>) {
         let mut topo = Vec::new();
         let mut visited = HashSet::new();
-        self.build_topo(&mut topo, &mut visited);
+        self.build_topo(&mut topo, &mut visited, boundary);
 
         for node in topo.iter().rev() {
-            let data = node.data.borrow();
-            if let Some(_backward_fn) = data.backward {
-                let value = data.value.clone();
-                let grad = data.grad.clone();
-                let children = data.children.clone();
-                let op = data.op;
-                drop(data);
-
-                match op {
-                    Op::Add => {
-                        // y = a + b -> da = dy, db = dy
-                        children[0].data.borrow_mut().grad += &grad;
-                        children[1].data.borrow_mut().grad += &grad;
-                    }
-                    Op::Mul => {
-                        // y = a * b -> da = dy * b^T, db = a^T * dy
-                        let v0 = children[0].data.borrow().value.clone();
-                        let v1 = children[1].data.borrow().value.clone();
-
-                        children[0].data.borrow_mut().grad += &grad.dot(&v1.t());
-                        children[1].data.borrow_mut().grad += &v0.t().dot(&grad);
-                    }
-                    Op::Tanh => {
-                        // y = tanh(x) -> dy/dx = 1 - tanh(x)^2
-                        let mut v0 = children[0].data.borrow_mut();
-
-                        let local_deriv = value.mapv(|x| 1.0 - x * x);
-
-                        v0.grad += &(&local_deriv * &grad);
-                    }
-                    Op::ReLU => {
-                        // y = relu(x) -> dy/dx = 1 if x > 0, 0 otherwise
-                        let mut v0 = children[0].data.borrow_mut();
-
-                        let mask = grad * value.mapv(|x| if x > 0.0 { 1.0 } else { 0.0 });
-
-                        v0.grad += &mask;
-                    }
-                    Op::None => {}
-                }
+            if Some(node.id()) == boundary {
+                continue;
+            }
+            let backward_fn = node.data.borrow().backward.clone();
+            if let Some(backward_fn) = backward_fn {
+                backward_fn();
             }
         }
     }
@@ -173,6 +453,43 @@ impl Autograd {
     pub fn set_grad(&self, grad: Array2<f64>) {
         self.data.borrow_mut().grad = grad;
     }
+
+    // Stable per-node identity, for keying external per-parameter state
+    // (e.g. optimizer momentum/moment buffers) without exposing the
+    // underlying `Rc`.
+    pub fn id(&self) -> usize {
+        Rc::as_ptr(&self.data) as usize
+    }
+}
+
+// A checkpointed node: `node` holds only its forward value and a handle to
+// its single input, not the intermediate ops that produced it. Recomputing
+// `f` during backward trades that extra forward pass for not keeping the
+// whole sub-graph's activations alive for the life of the outer graph.
+pub struct Checkpoint {
+    pub node: Autograd,
+}
+
+// Wraps `f(input)` as a checkpoint: `f` runs once now to produce the forward
+// value, then its intermediate nodes are dropped; `f` runs again during
+// backward, against a freshly recomputed sub-graph, to produce the gradient
+// pushed into `input`. No guard is needed around that recompute: `build_topo`'s
+// `visited` set already ensures a node's backward rule runs at most once per
+// `backward()`/`backward_bounded()` call, so this closure itself only ever
+// runs once per pass, and naturally runs again on the next one.
+pub fn checkpoint(input: &Autograd, f: impl Fn(&Autograd) -> Autograd + 'static) -> Checkpoint {
+    let value = f(input).value();
+
+    let input = input.clone();
+    let f = Rc::new(f);
+
+    let node = Autograd::new_op(value, vec![input.clone()], move |grad| {
+        let recomputed = f(&input);
+        recomputed.set_grad(grad.clone());
+        recomputed.backward_bounded(Some(input.id()));
+    });
+
+    Checkpoint { node }
 }
 
 impl Clone for Autograd {
@@ -183,15 +500,181 @@ impl Clone for Autograd {
     }
 }
 
+impl std::ops::Add for &Autograd {
+    type Output = Autograd;
+    fn add(self, other: &Autograd) -> Autograd {
+        Autograd::add(self, other)
+    }
+}
+
+impl std::ops::Add for Autograd {
+    type Output = Autograd;
+    fn add(self, other: Autograd) -> Autograd {
+        Autograd::add(&self, &other)
+    }
+}
+
+impl std::ops::Sub for &Autograd {
+    type Output = Autograd;
+    fn sub(self, other: &Autograd) -> Autograd {
+        Autograd::sub(self, other)
+    }
+}
+
+impl std::ops::Sub for Autograd {
+    type Output = Autograd;
+    fn sub(self, other: Autograd) -> Autograd {
+        Autograd::sub(&self, &other)
+    }
+}
+
+impl std::ops::Mul for &Autograd {
+    type Output = Autograd;
+    fn mul(self, other: &Autograd) -> Autograd {
+        Autograd::mul(self, other)
+    }
+}
+
+impl std::ops::Mul for Autograd {
+    type Output = Autograd;
+    fn mul(self, other: Autograd) -> Autograd {
+        Autograd::mul(&self, &other)
+    }
+}
+
+impl std::ops::Div for &Autograd {
+    type Output = Autograd;
+    fn div(self, other: &Autograd) -> Autograd {
+        Autograd::div(self, other)
+    }
+}
+
+impl std::ops::Div for Autograd {
+    type Output = Autograd;
+    fn div(self, other: Autograd) -> Autograd {
+        Autograd::div(&self, &other)
+    }
+}
+
+impl std::ops::Neg for &Autograd {
+    type Output = Autograd;
+    fn neg(self) -> Autograd {
+        Autograd::neg(self)
+    }
+}
+
+impl std::ops::Neg for Autograd {
+    type Output = Autograd;
+    fn neg(self) -> Autograd {
+        Autograd::neg(&self)
+    }
+}
+
 impl std::fmt::Debug for Autograd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let data = self.data.borrow();
         f.debug_struct("Autograd")
             .field("value", &data.value)
             .field("grad", &data.grad)
-            .field("children", &data.children)
+            .field("parents", &data.parents.as_ref().map(|_| "Fn"))
             .field("op", &data.op)
             .field("backward", &data.backward.as_ref().map(|_| "Fn"))
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Central-difference estimate of d(sum(f(x)))/dx[idx], for checking an
+    // op's analytic backward rule against the numeric gradient of a scalar
+    // reduction of its output.
+    fn numerical_grad(x: &Array2<f64>, idx: (usize, usize), f: impl Fn(&Array2<f64>) -> f64) -> f64 {
+        let eps = 1e-6;
+        let mut plus = x.clone();
+        plus[idx] += eps;
+        let mut minus = x.clone();
+        minus[idx] -= eps;
+        (f(&plus) - f(&minus)) / (2.0 * eps)
+    }
+
+    #[test]
+    fn div_backward_matches_finite_difference_under_broadcast() {
+        // b is (1,1) and broadcasts against a's (2,2) in the forward pass;
+        // this is the shape mismatch that used to panic in backward.
+        let a_val = Array2::from_shape_vec((2, 2), vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        let b_val = Array2::from_elem((1, 1), 2.0);
+
+        let a = Autograd::new(a_val.clone());
+        let b = Autograd::new(b_val.clone());
+        let out = a.div(&b);
+        out.set_grad(Array2::ones((2, 2)));
+        out.backward();
+
+        for idx in [(0, 0), (1, 1)] {
+            let numeric = numerical_grad(&a_val, idx, |v| (v / &b_val).sum());
+            assert!(
+                (a.grad()[idx] - numeric).abs() < 1e-4,
+                "a.grad{idx:?} = {}, expected ~{numeric}",
+                a.grad()[idx]
+            );
+        }
+
+        let numeric_b = numerical_grad(&b_val, (0, 0), |v| (&a_val / v).sum());
+        assert!(
+            (b.grad()[[0, 0]] - numeric_b).abs() < 1e-4,
+            "b.grad = {}, expected ~{numeric_b}",
+            b.grad()[[0, 0]]
+        );
+    }
+
+    #[test]
+    fn mul_backward_matches_finite_difference() {
+        let a_val = Array2::from_shape_vec((2, 3), (1..=6).map(|x| x as f64).collect()).unwrap();
+        let b_val = Array2::from_shape_vec((3, 2), vec![0.5, -1.0, 2.0, 3.0, -0.5, 1.5]).unwrap();
+
+        let a = Autograd::new(a_val.clone());
+        let b = Autograd::new(b_val.clone());
+        let out = a.mul(&b);
+        out.set_grad(Array2::ones((2, 2)));
+        out.backward();
+
+        let numeric = numerical_grad(&a_val, (1, 2), |v| v.dot(&b_val).sum());
+        assert!((a.grad()[[1, 2]] - numeric).abs() < 1e-4);
+
+        let numeric = numerical_grad(&b_val, (2, 0), |v| a_val.dot(v).sum());
+        assert!((b.grad()[[2, 0]] - numeric).abs() < 1e-4);
+    }
+
+    #[test]
+    fn checkpoint_backward_matches_uncheckpointed() {
+        let x_val = Array2::from_shape_vec((2, 1), vec![0.3, -0.7]).unwrap();
+        let w_val = Array2::from_shape_vec((2, 2), vec![0.1, 0.2, -0.3, 0.4]).unwrap();
+
+        let run = |checkpointed: bool| {
+            let x = Autograd::new(x_val.clone());
+            let w = Autograd::new(w_val.clone());
+            let w_for_closure = w.clone();
+
+            let hidden_fn = move |inp: &Autograd| w_for_closure.mul(inp).tanh();
+            let hidden = if checkpointed {
+                checkpoint(&x, hidden_fn).node
+            } else {
+                hidden_fn(&x)
+            };
+            let out = hidden.add(&hidden);
+            out.set_grad(Array2::ones((2, 1)));
+            out.backward();
+
+            w.grad()
+        };
+
+        let normal = run(false);
+        let via_checkpoint = run(true);
+        assert!(
+            (normal - via_checkpoint).mapv(f64::abs).sum() < 1e-9,
+            "checkpointed backward should match the uncheckpointed gradient"
+        );
+    }
+}
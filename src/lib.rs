@@ -0,0 +1,4 @@
+pub mod autograd;
+pub mod loss;
+pub mod mlp;
+pub mod optim;
@@ -0,0 +1,212 @@
+use ndarray::Array2;
+
+use crate::autograd::Autograd;
+
+// Mean squared error: mean((pred - target)^2), reduced to a scalar (1,1)
+// node, the same convention `softmax_cross_entropy` uses — seed it with a
+// (1,1) grad of 1.0 rather than one shaped like the batch.
+pub fn mse(pred: &Autograd, target: &Autograd) -> Autograd {
+    let diff = pred.value() - target.value();
+    let n = diff.len() as f64;
+    let loss_value = Array2::from_elem((1, 1), diff.mapv(|d| d * d).sum() / n);
+
+    let p = pred.clone();
+    let t = target.clone();
+
+    Autograd::new_op(loss_value, vec![pred.clone(), target.clone()], move |grad| {
+        let upstream = grad[[0, 0]];
+        let diff = p.value() - t.value();
+        let local_grad = diff.mapv(|d| 2.0 * d / n * upstream);
+
+        p.set_grad(p.grad() + &local_grad);
+        t.set_grad(t.grad() - &local_grad);
+    })
+}
+
+// Binary cross-entropy: mean(-(y*ln(p) + (1-y)*ln(1-p))), reduced to a
+// scalar (1,1) node — the same convention `mse` and `softmax_cross_entropy`
+// use — seed it with a (1,1) grad of 1.0 rather than one shaped like the
+// batch.
+pub fn bce(pred: &Autograd, target: &Autograd) -> Autograd {
+    let p_val = pred.value();
+    let y_val = target.value();
+    let n = p_val.len() as f64;
+
+    let per_elem = Array2::from_shape_fn(p_val.raw_dim(), |idx| {
+        let (p, y) = (p_val[idx], y_val[idx]);
+        -(y * p.ln() + (1.0 - y) * (1.0 - p).ln())
+    });
+    let loss_value = Array2::from_elem((1, 1), per_elem.sum() / n);
+
+    let p = pred.clone();
+    let t = target.clone();
+
+    Autograd::new_op(loss_value, vec![pred.clone(), target.clone()], move |grad| {
+        let upstream = grad[[0, 0]];
+        let p_val = p.value();
+        let y_val = t.value();
+
+        let dp = Array2::from_shape_fn(p_val.raw_dim(), |idx| {
+            let (pv, yv) = (p_val[idx], y_val[idx]);
+            -(yv / pv - (1.0 - yv) / (1.0 - pv)) / n * upstream
+        });
+        let dy = Array2::from_shape_fn(p_val.raw_dim(), |idx| {
+            let pv = p_val[idx];
+            ((1.0 - pv).ln() - pv.ln()) / n * upstream
+        });
+
+        p.set_grad(p.grad() + &dp);
+        t.set_grad(t.grad() + &dy);
+    })
+}
+
+// Fused softmax + cross-entropy over a set of scalar logits. Computes
+// `p_i = softmax(logits)_i` with the usual max-subtraction for numerical
+// stability, returns `-ln(p_target_class)`, and in backward pushes the
+// well-known simplified gradient `dL/dz_i = p_i - y_i` directly into the
+// logits, instead of composing it out of separate `exp`/`ln`/`div` ops.
+pub fn softmax_cross_entropy(logits: &[Autograd], target_class: usize) -> Autograd {
+    assert!(!logits.is_empty(), "softmax_cross_entropy: logits must not be empty");
+    assert!(
+        target_class < logits.len(),
+        "softmax_cross_entropy: target_class {target_class} out of range for {} logits",
+        logits.len()
+    );
+
+    let values: Vec<f64> = logits.iter().map(|l| l.value()[[0, 0]]).collect();
+    let max_logit = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let exps: Vec<f64> = values.iter().map(|&v| (v - max_logit).exp()).collect();
+    let sum_exp: f64 = exps.iter().sum();
+    let probs: Vec<f64> = exps.iter().map(|&e| e / sum_exp).collect();
+
+    let loss_value = Array2::from_elem((1, 1), -probs[target_class].ln());
+    let children = logits.to_vec();
+
+    Autograd::new_op(loss_value, logits.to_vec(), move |grad| {
+        let upstream = grad[[0, 0]];
+        for (i, logit) in children.iter().enumerate() {
+            let y_i = if i == target_class { 1.0 } else { 0.0 };
+            let local_grad = upstream * (probs[i] - y_i);
+            logit.set_grad(logit.grad() + Array2::from_elem((1, 1), local_grad));
+        }
+    })
+}
+
+// Plugs in a user-supplied loss `fn(target, pred) -> L` and its derivative
+// `fn(target, pred) -> dL/dpred` instead of building the loss out of
+// `Autograd` ops, for losses whose value and gradient are known in closed
+// form but aren't worth wiring up as a new op. `pred` must be a scalar
+// (1,1) node, typically a network output.
+pub fn custom_derivative(
+    pred: &Autograd,
+    target: f64,
+    value: impl Fn(f64, f64) -> f64,
+    deriv: impl Fn(f64, f64) -> f64 + 'static,
+) -> Autograd {
+    let pred_value = pred.value()[[0, 0]];
+    let loss_value = Array2::from_elem((1, 1), value(target, pred_value));
+    let p = pred.clone();
+
+    Autograd::new_op(loss_value, vec![pred.clone()], move |grad| {
+        let upstream = grad[[0, 0]];
+        let local_grad = deriv(target, pred_value);
+        p.set_grad(p.grad() + Array2::from_elem((1, 1), upstream * local_grad));
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numerical_grad(x: &Array2<f64>, idx: (usize, usize), f: impl Fn(&Array2<f64>) -> f64) -> f64 {
+        let eps = 1e-6;
+        let mut plus = x.clone();
+        plus[idx] += eps;
+        let mut minus = x.clone();
+        minus[idx] -= eps;
+        (f(&plus) - f(&minus)) / (2.0 * eps)
+    }
+
+    #[test]
+    fn mse_grad_matches_finite_difference() {
+        let pred_val = Array2::from_shape_vec((1, 3), vec![0.2, 0.5, 0.9]).unwrap();
+        let target_val = Array2::from_shape_vec((1, 3), vec![0.0, 1.0, 1.0]).unwrap();
+
+        let pred = Autograd::new(pred_val.clone());
+        let target = Autograd::new(target_val.clone());
+        let loss = mse(&pred, &target);
+        loss.set_grad(Array2::ones((1, 1)));
+        loss.backward();
+
+        let numeric = numerical_grad(&pred_val, (0, 1), |v| {
+            let diff = v - &target_val;
+            (diff.mapv(|d| d * d).sum()) / v.len() as f64
+        });
+        assert!((pred.grad()[[0, 1]] - numeric).abs() < 1e-4);
+    }
+
+    #[test]
+    fn bce_reduces_to_scalar_and_matches_finite_difference() {
+        let pred_val = Array2::from_shape_vec((1, 3), vec![0.2, 0.5, 0.9]).unwrap();
+        let target_val = Array2::from_shape_vec((1, 3), vec![0.0, 1.0, 1.0]).unwrap();
+
+        let pred = Autograd::new(pred_val.clone());
+        let target = Autograd::new(target_val.clone());
+        let loss = bce(&pred, &target);
+        assert_eq!(loss.value().shape(), &[1, 1]);
+
+        loss.set_grad(Array2::ones((1, 1)));
+        loss.backward();
+
+        let numeric = numerical_grad(&pred_val, (0, 0), |v| {
+            let n = v.len() as f64;
+            v.iter()
+                .zip(target_val.iter())
+                .map(|(&p, &y)| -(y * p.ln() + (1.0 - y) * (1.0 - p).ln()))
+                .sum::<f64>()
+                / n
+        });
+        assert!((pred.grad()[[0, 0]] - numeric).abs() < 1e-4);
+    }
+
+    #[test]
+    fn softmax_cross_entropy_grad_matches_finite_difference() {
+        let values = [0.5, -1.0, 2.0];
+        let target_class = 2;
+
+        let logits: Vec<Autograd> = values.iter().map(|&v| Autograd::new(Array2::from_elem((1, 1), v))).collect();
+        let loss = softmax_cross_entropy(&logits, target_class);
+        loss.set_grad(Array2::ones((1, 1)));
+        loss.backward();
+
+        let loss_of = |vals: &[f64]| -> f64 {
+            let max = vals.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let exps: Vec<f64> = vals.iter().map(|&v| (v - max).exp()).collect();
+            let sum_exp: f64 = exps.iter().sum();
+            -((exps[target_class] / sum_exp).ln())
+        };
+
+        let eps = 1e-6;
+        for i in 0..values.len() {
+            let mut plus = values;
+            plus[i] += eps;
+            let mut minus = values;
+            minus[i] -= eps;
+            let numeric = (loss_of(&plus) - loss_of(&minus)) / (2.0 * eps);
+            assert!((logits[i].grad()[[0, 0]] - numeric).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be empty")]
+    fn softmax_cross_entropy_rejects_empty_logits() {
+        softmax_cross_entropy(&[], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn softmax_cross_entropy_rejects_out_of_range_target() {
+        let logits = [Autograd::new(Array2::from_elem((1, 1), 1.0))];
+        softmax_cross_entropy(&logits, 1);
+    }
+}
@@ -1,7 +1,7 @@
 use ndarray::Array2;
 use rand::Rng;
 
-use crate::autograd::Autograd;
+use crate::autograd::{checkpoint, Autograd, Checkpoint};
 
 #[derive(Debug, Clone, Copy)]
 pub enum Activation {
@@ -10,68 +10,55 @@ pub enum Activation {
     None,
 }
 
-pub struct Neuron {
-    weights: Vec<Autograd>,
-    bias: Autograd,
-}
-
-impl Neuron {
-    pub fn new(nin: usize) -> Self {
-        let mut rng = rand::thread_rng();
-
-        let scale = (2.0 / nin as f64).sqrt();
-        let weights = (0..nin)
-            .map(|_| Autograd::new(Array2::from_elem((1, 1), rng.gen_range(-scale..scale))))
-            .collect();
-        let bias = Autograd::new(Array2::from_elem((1, 1), 0.0));
-
-        Self { weights, bias }
-    }
-
-    pub fn call(&self, x: &[Autograd], activation: Activation) -> Autograd {
-        let mut sum = self.bias.clone();
-        for (w, xi) in self.weights.iter().zip(x.iter()) {
-            // sum = sum + w * xi
-            sum = sum.add(&w.mul(xi));
-        }
-
-        match activation {
-            Activation::ReLU => sum.relu(),
-            Activation::Tanh => sum.tanh(),
-            Activation::None => sum,
-        }
-    }
-
-    pub fn parameters(&self) -> Vec<Autograd> {
-        let mut params = self.weights.clone();
-        params.push(self.bias.clone());
-        params
-    }
-}
-
 pub struct Layer {
-    neurons: Vec<Neuron>,
+    weight: Autograd, // (nout, nin)
+    bias: Autograd,   // (nout, 1), broadcast across the batch dimension
     activation: Activation,
 }
 
 impl Layer {
     pub fn new(nin: usize, nout: usize, activation: Activation) -> Self {
-        let neurons = (0..nout).map(|_| Neuron::new(nin)).collect();
+        let mut rng = rand::thread_rng();
+
+        let scale = (2.0 / nin as f64).sqrt();
+        let weight = Array2::from_shape_fn((nout, nin), |_| rng.gen_range(-scale..scale));
+        let bias = Array2::zeros((nout, 1));
+
         Self {
-            neurons,
+            weight: Autograd::new(weight),
+            bias: Autograd::new(bias),
             activation,
         }
     }
 
-    pub fn call(&self, x: &[Autograd]) -> Vec<Autograd> {
-        self.neurons
-            .iter()
-            .map(|n| n.call(x, self.activation))
-            .collect()
+    // x: (nin, batch) -> (nout, batch)
+    pub fn call(&self, x: &Autograd) -> Autograd {
+        (self.as_closure())(x)
     }
 
     pub fn parameters(&self) -> Vec<Autograd> {
-        self.neurons.iter().flat_map(|n| n.parameters()).collect()
+        vec![self.weight.clone(), self.bias.clone()]
+    }
+
+    // The layer's forward computation, as a standalone closure over cloned
+    // (cheap, `Rc`-backed) parameter handles, so it can be recomputed later
+    // without borrowing `self`. `call` is defined in terms of this so the
+    // checkpointed and non-checkpointed forward passes can't drift apart.
+    // Used directly by `MLP::call_checkpointed`.
+    fn as_closure(&self) -> impl Fn(&Autograd) -> Autograd + 'static {
+        let weight = self.weight.clone();
+        let bias = self.bias.clone();
+        let activation = self.activation;
+
+        move |x: &Autograd| {
+            let z = weight.mul(x).add(&bias);
+
+            match activation {
+                Activation::ReLU => z.relu(),
+                Activation::Tanh => z.tanh(),
+                Activation::None => z,
+            }
+        }
     }
 }
 
@@ -98,14 +85,32 @@ impl MLP {
         Self { layers }
     }
 
-    pub fn call(&self, x: &[Autograd]) -> Vec<Autograd> {
-        let mut current = x.to_vec();
+    // x: (nin, batch) -> (nout, batch)
+    pub fn call(&self, x: &Autograd) -> Autograd {
+        let mut current = x.clone();
         for layer in &self.layers {
             current = layer.call(&current);
         }
         current
     }
 
+    // Like `call`, but checkpoints each layer's activations: only each
+    // layer's input and output values are kept, not the ops in between, at
+    // the cost of recomputing each layer's forward pass once more during
+    // `backward()`. Bounds peak memory for nets with many/large layers.
+    pub fn call_checkpointed(&self, x: &Autograd) -> Checkpoint {
+        let mut current = x.clone();
+        let mut last = None;
+
+        for layer in &self.layers {
+            let ckpt = checkpoint(&current, layer.as_closure());
+            current = ckpt.node.clone();
+            last = Some(ckpt);
+        }
+
+        last.expect("MLP must have at least one layer")
+    }
+
     pub fn parameters(&self) -> Vec<Autograd> {
         self.layers.iter().flat_map(|l| l.parameters()).collect()
     }
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use ndarray::Array2;
+
+use crate::autograd::Autograd;
+
+pub trait Optimizer {
+    fn step(&mut self);
+    fn zero_grad(&self);
+}
+
+pub struct Sgd {
+    params: Vec<Autograd>,
+    lr: f64,
+    momentum: f64,
+    velocity: HashMap<usize, Array2<f64>>,
+}
+
+impl Sgd {
+    pub fn new(params: Vec<Autograd>, lr: f64, momentum: f64) -> Self {
+        Self {
+            params,
+            lr,
+            momentum,
+            velocity: HashMap::new(),
+        }
+    }
+}
+
+impl Optimizer for Sgd {
+    fn step(&mut self) {
+        for p in &self.params {
+            let grad = p.grad();
+            let velocity = self
+                .velocity
+                .entry(p.id())
+                .or_insert_with(|| Array2::zeros(grad.raw_dim()));
+            *velocity = &*velocity * self.momentum + &grad;
+
+            p.set_value(p.value() - &*velocity * self.lr);
+        }
+    }
+
+    fn zero_grad(&self) {
+        for p in &self.params {
+            p.zero_grad();
+        }
+    }
+}
+
+pub struct Adam {
+    params: Vec<Autograd>,
+    lr: f64,
+    beta1: f64,
+    beta2: f64,
+    eps: f64,
+    m: HashMap<usize, Array2<f64>>,
+    v: HashMap<usize, Array2<f64>>,
+    t: i32,
+}
+
+impl Adam {
+    pub fn new(params: Vec<Autograd>, lr: f64, beta1: f64, beta2: f64, eps: f64) -> Self {
+        Self {
+            params,
+            lr,
+            beta1,
+            beta2,
+            eps,
+            m: HashMap::new(),
+            v: HashMap::new(),
+            t: 0,
+        }
+    }
+}
+
+impl Optimizer for Adam {
+    fn step(&mut self) {
+        self.t += 1;
+
+        for p in &self.params {
+            let grad = p.grad();
+
+            let m = self
+                .m
+                .entry(p.id())
+                .or_insert_with(|| Array2::zeros(grad.raw_dim()));
+            *m = &*m * self.beta1 + &grad * (1.0 - self.beta1);
+
+            let v = self
+                .v
+                .entry(p.id())
+                .or_insert_with(|| Array2::zeros(grad.raw_dim()));
+            *v = &*v * self.beta2 + &grad.mapv(|g| g * g) * (1.0 - self.beta2);
+
+            let m_hat = &*m / (1.0 - self.beta1.powi(self.t));
+            let v_hat = &*v / (1.0 - self.beta2.powi(self.t));
+
+            p.set_value(p.value() - &(&m_hat / &(v_hat.mapv(f64::sqrt) + self.eps)) * self.lr);
+        }
+    }
+
+    fn zero_grad(&self) {
+        for p in &self.params {
+            p.zero_grad();
+        }
+    }
+}